@@ -1,4 +1,7 @@
-import { create, indexOf, generationOf } from '../id';
+import {
+  create, tryCreate, indexOf, generationOf, nextGeneration, saturatingNextGeneration, toBytes, fromBytes,
+  MAX_SAFE_GENERATION
+} from '../id';
 
 describe('GenId', () => {
   it('should create correct id from index and generation values', () => {
@@ -6,6 +9,16 @@ describe('GenId', () => {
     expect(create(7, 3)).toBe(12884901895);
   });
 
+  it('should try to create id only for index and generation in range', () => {
+    expect(tryCreate(7, 3)).toBe(create(7, 3));
+    expect(tryCreate(7, MAX_SAFE_GENERATION)).toBe(create(7, MAX_SAFE_GENERATION));
+    expect(tryCreate(2 ** 32 - 1, 1)).toBe(create(2 ** 32 - 1, 1));
+    expect(tryCreate(7, 1 << 22)).toBeUndefined();
+    expect(tryCreate(2 ** 32, 1)).toBeUndefined();
+    expect(tryCreate(-1, 1)).toBeUndefined();
+    expect(tryCreate(0.5, 1)).toBeUndefined();
+  });
+
   it('should get the correct index from valid generational id', () => {
     expect(indexOf(12884901899)).toBe(11);
   });
@@ -13,4 +26,47 @@ describe('GenId', () => {
   it('should get the correct generation from valid generational id', () => {
     expect(generationOf(21474836493)).toBe(5);
   });
+
+  it('should wrap the next generation to 1 after max generation', () => {
+    expect(nextGeneration(3)).toBe(4);
+    expect(nextGeneration(MAX_SAFE_GENERATION)).toBe(1);
+  });
+
+  it('should saturate the next generation at max generation', () => {
+    expect(saturatingNextGeneration(3)).toBe(4);
+    expect(saturatingNextGeneration(MAX_SAFE_GENERATION - 1)).toBe(MAX_SAFE_GENERATION);
+    expect(saturatingNextGeneration(MAX_SAFE_GENERATION)).toBe(MAX_SAFE_GENERATION);
+  });
+
+  it('should round-trip through big-endian and little-endian bytes', () => {
+    const id = create(7, 3);
+    const be = toBytes(id);
+    const le = toBytes(id, true);
+
+    expect(be.length).toBe(8);
+    expect([...le]).toStrictEqual([...be].reverse());
+    expect(fromBytes(be)).toBe(id);
+    expect(fromBytes(le, true)).toBe(id);
+  });
+
+  it.each([
+    ['NaN', new Uint8Array(8).fill(0xff)],
+    ['negative', toBytes(-1)],
+    ['fractional', toBytes(create(7, 3) + 0.5)],
+    ['out-of-range generation', toBytes((MAX_SAFE_GENERATION + 1) * 2 ** 32)],
+  ])('should reject bytes that encode a %s id', (_, bytes) => {
+    expect(fromBytes(bytes)).toBeUndefined();
+  });
+
+  it.each([
+    ['short', new Uint8Array(4)],
+    ['subarray', (() => {
+      const bytes = new Uint8Array(16);
+      bytes.set(toBytes(create(7, 3)));
+      return bytes.subarray(0, 4);
+    })()],
+    ['over-long', new Uint8Array([...toBytes(create(7, 3)), 0])],
+  ])('should reject %s bytes input', (_, bytes) => {
+    expect(fromBytes(bytes)).toBeUndefined();
+  });
 });
@@ -1,4 +1,4 @@
-import { uniqueJoin } from '../query';
+import { diffKeys, getMany, uniqueJoin } from '../query';
 
 describe('uniqueJoin', () => {
   it('should perform left join by key', () => {
@@ -26,3 +26,22 @@ describe('uniqueJoin', () => {
     ]);
   })
 });
+
+describe('getMany', () => {
+  it('should get values of all keys in order', () => {
+    const map = new Map<string, number>();
+    map.set('a', 1);
+    map.set('b', 2);
+
+    expect([...getMany(map, ['b', 'c', 'a'])]).toStrictEqual([2, undefined, 1]);
+  });
+});
+
+describe('diffKeys', () => {
+  it('should return added, removed and common keys', () => {
+    const prev = new Map([[1, 'a'], [2, 'b'], [3, 'c']]);
+    const next = new Map([[2, 'b'], [3, 'd'], [4, 'e']]);
+
+    expect(diffKeys(prev, next)).toStrictEqual([[4], [1], [2, 3]]);
+  });
+});
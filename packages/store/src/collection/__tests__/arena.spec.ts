@@ -1,4 +1,5 @@
 import { GenerationalArena } from '../arena';
+import * as GenId from '../../id';
 
 describe('GenerationalArena', () => {
   test('add value to arena', () => {
@@ -13,6 +14,65 @@ describe('GenerationalArena', () => {
     expect(arena.has(id)).toBeTruthy();
   });
 
+  test('add() should use initial generation for new keys', () => {
+    const arena = new GenerationalArena<string>(5);
+    const id = arena.add('hello');
+
+    expect(GenId.generationOf(id)).toBe(5);
+    expect(arena.get(id)).toBe('hello');
+  });
+
+  test('tryAdd() should add value unless reused slot generation is exhausted', () => {
+    const arena = new GenerationalArena<string>();
+    arena['ids']['generations'].push(GenId.MAX_SAFE_GENERATION);
+    arena.delete(GenId.create(0, GenId.MAX_SAFE_GENERATION));
+
+    expect(arena.tryAdd('stale')).toBeUndefined();
+    expect(arena.size).toBe(0);
+
+    const id = arena.tryAdd('hello');
+    expect(id).toBe(GenId.create(1, 0));
+    expect(arena.get(id as number)).toBe('hello');
+  });
+
+  test('generationAt() should return generation of live key at index', () => {
+    const arena = new GenerationalArena<string>();
+    const id = arena.add('hello');
+
+    expect(arena.generationAt(GenId.indexOf(id))).toBe(GenId.generationOf(id));
+    arena.delete(id);
+    expect(arena.generationAt(GenId.indexOf(id))).toBeUndefined();
+  });
+
+  test('addWithKey() should add value created from its id', () => {
+    const arena = new GenerationalArena<{ id: number }>();
+    const id = arena.addWithKey((id) => ({ id }));
+
+    expect(arena.size).toBe(1);
+    expect(arena.get(id)?.id).toBe(id);
+  });
+
+  test('addWithKey() should release id if value creation throws', () => {
+    const arena = new GenerationalArena<string>();
+    let failedId = 0;
+
+    expect(() => arena.addWithKey((id) => {
+      failedId = id;
+      throw new Error('failed');
+    })).toThrow('failed');
+    expect(arena.size).toBe(0);
+    expect(arena.has(failedId)).toBeFalsy();
+  });
+
+  test('addMany() should add values and return their ids in order', () => {
+    const arena = new GenerationalArena<string>();
+    const ids = arena.addMany(['a', 'b', 'c']);
+
+    expect(ids.length).toBe(3);
+    expect(arena.size).toBe(3);
+    expect(ids.map((id) => arena.get(id))).toEqual(['a', 'b', 'c']);
+  });
+
   test('clear() should empty the container', () => {
     const arena = new GenerationalArena<string>();
     arena.add('1');
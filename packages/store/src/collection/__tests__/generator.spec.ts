@@ -31,6 +31,71 @@ describe('IdGenerator', () => {
     expect(generator.add()).toBe(GenId.create(0, 1));
   });
 
+  test('add() should use initial generation for new indices', () => {
+    const generator = new IdGenerator(5);
+
+    expect(generator.add()).toBe(GenId.create(0, 5));
+    expect(generator.add()).toBe(GenId.create(1, 5));
+  });
+
+  test.each([
+    [-1, 0],
+    [2.5, 2],
+    [NaN, 0],
+    [2 ** 21, GenId.MAX_SAFE_GENERATION],
+  ])('constructor should clamp initial generation %d to %d', (initialGeneration, expected) => {
+    const generator = new IdGenerator(initialGeneration);
+    generator.add();
+    const id = generator.add();
+
+    expect(GenId.generationOf(id)).toBe(expected);
+    expect(generator.has(id)).toBeTruthy();
+    expect([...generator]).toContain(id);
+  });
+
+  test('constructor should never produce 0 id for out-of-range initial generation', () => {
+    const generator = new IdGenerator(2 ** 21);
+    const id = generator.add();
+
+    expect(id).toBeTruthy();
+    expect(generator.has(id)).toBeTruthy();
+  });
+
+  test('tryAdd() should retire index with exhausted generation', () => {
+    const generator = new IdGenerator();
+    generator['generations'].push(GenId.MAX_SAFE_GENERATION);
+    const staleId = GenId.create(0, GenId.MAX_SAFE_GENERATION);
+
+    expect(generator.delete(staleId)).toBeTruthy();
+    expect(generator.tryAdd()).toBeUndefined();
+    expect(generator.size).toBe(0);
+    expect(generator.has(GenId.create(0, 1))).toBeFalsy();
+
+    const id = generator.tryAdd();
+    expect(id).toBe(GenId.create(1, 0));
+    expect(generator.size).toBe(1);
+    expect([...generator]).toEqual([id]);
+  });
+
+  test('tryAdd() should reuse index with available generation', () => {
+    const generator = new IdGenerator();
+    generator.delete(generator.add());
+
+    expect(generator.tryAdd()).toBe(GenId.create(0, 2));
+  });
+
+  test('generationAt() should return generation of live id at index', () => {
+    const generator = new IdGenerator();
+    const id1 = generator.add();
+    const id2 = generator.add();
+    generator.delete(id2);
+
+    expect(generator.generationAt(GenId.indexOf(id1))).toBe(GenId.generationOf(id1));
+    expect(generator.generationAt(GenId.indexOf(id2))).toBeUndefined();
+    expect(generator.generationAt(99)).toBeUndefined();
+    expect(generator.generationAt(-1)).toBeUndefined();
+  });
+
   test('clear() should empty the container', () => {
     const generator = new IdGenerator();
     generator.add();
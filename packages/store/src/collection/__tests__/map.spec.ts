@@ -5,6 +5,14 @@ describe.each([
   ['GenIdMap', GenIdMap],
   ['SparseSetMap', SparseSetMap]
 ])('%s', (_, MapType) => {
+  test('constructor should populate map from entries', () => {
+    const id1 = GenId.create(0, 1), id2 = GenId.create(10, 2);
+    const map = new MapType<string>([[id1, 'hello'], [id2, 'world']]);
+
+    expect(map.size).toBe(2);
+    expect([...map]).toEqual([[id1, 'hello'], [id2, 'world']]);
+  });
+
   test('clear() should empty the container', () => {
     const map = new MapType<string>();
     map.set(GenId.create(0, 1), '1');
@@ -134,3 +142,84 @@ describe.each([
     expect(results).toEqual([value1, value2]);
   });
 });
+
+describe('SparseSetMap', () => {
+  test('deleteMany() should remove values and return number deleted', () => {
+    const id1 = GenId.create(0, 1), id2 = GenId.create(10, 2), id3 = GenId.create(5, 1);
+    const map = new SparseSetMap<string>([[id1, 'a'], [id2, 'b'], [id3, 'c']]);
+
+    expect(map.deleteMany([id1, GenId.create(10, 1), id3, id3])).toBe(2);
+    expect([...map]).toEqual([[id2, 'b']]);
+  });
+
+  test('hasIndex() should check index regardless of generation', () => {
+    const id = GenId.create(3, 2);
+    const map = new SparseSetMap<string>([[id, 'a']]);
+
+    expect(map.has(GenId.create(3, 1))).toBeFalsy();
+    expect(map.hasIndex(3)).toBeTruthy();
+    expect(map.hasIndex(0)).toBeFalsy();
+
+    map.delete(id);
+    expect(map.hasIndex(3)).toBeFalsy();
+  });
+
+  test('shrinkSparse() should shrink sparse array to largest live index', () => {
+    const id1 = GenId.create(3, 1), id2 = GenId.create(1000, 1);
+    const map = new SparseSetMap<string>([[id1, 'a'], [id2, 'b']]);
+    map.delete(id2);
+
+    map.shrinkSparse();
+    expect(map['sparse'].length).toBe(4);
+    expect([...map]).toEqual([[id1, 'a']]);
+
+    map.set(id2, 'c');
+    expect(map.get(id2)).toBe('c');
+    expect(map.get(id1)).toBe('a');
+  });
+
+  test('shrinkSparse() should empty sparse array of empty map', () => {
+    const map = new SparseSetMap<string>([[GenId.create(10, 1), 'a']]);
+    map.clear();
+    map.set(GenId.create(10, 1), 'b');
+    map.truncate(0);
+
+    map.shrinkSparse();
+    expect(map['sparse'].length).toBe(0);
+  });
+
+  test('truncate() should remove the last entries', () => {
+    const id1 = GenId.create(0, 1), id2 = GenId.create(10, 2), id3 = GenId.create(5, 1);
+    const map = new SparseSetMap<string>([[id1, 'a'], [id2, 'b']]);
+    const checkpoint = map.size;
+    map.set(id3, 'c');
+
+    map.truncate(checkpoint);
+    expect(map.size).toBe(2);
+    expect(map.has(id3)).toBeFalsy();
+    expect([...map]).toEqual([[id1, 'a'], [id2, 'b']]);
+
+    map.set(id3, 'd');
+    expect(map.get(id3)).toBe('d');
+  });
+
+  test.each([10, -1, 0.5, NaN])('truncate() should do nothing for invalid length %d', (length) => {
+    const id = GenId.create(0, 1);
+    const map = new SparseSetMap<string>([[id, 'a']]);
+    map.truncate(length);
+
+    expect(map.size).toBe(1);
+    expect(map.get(id)).toBe('a');
+  });
+});
+
+describe('GenIdMap and SparseSetMap', () => {
+  test('should convert into each other', () => {
+    const id1 = GenId.create(0, 1), id2 = GenId.create(10, 2);
+    const sparseSet = new SparseSetMap<string>([[id1, 'hello'], [id2, 'world']]);
+
+    const genIdMap = new GenIdMap(sparseSet);
+    expect([...genIdMap]).toEqual([[id1, 'hello'], [id2, 'world']]);
+    expect([...new SparseSetMap(genIdMap)]).toEqual([...sparseSet]);
+  });
+});
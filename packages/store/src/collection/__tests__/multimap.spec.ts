@@ -0,0 +1,88 @@
+import { GenIdMultiMap } from '../multimap';
+import * as GenId from '../../id';
+
+describe('GenIdMultiMap', () => {
+  test('add() should append values to key', () => {
+    const map = new GenIdMultiMap<string>();
+    const id = GenId.create(0, 1);
+    expect(map.add(id, 'hello')).toBe(map);
+    map.add(id, 'world');
+
+    expect(map.size).toBe(1);
+    expect(map.has(id)).toBeTruthy();
+    expect(map.getAll(id)).toEqual(['hello', 'world']);
+  });
+
+  test('add() should replace values of previous generation', () => {
+    const map = new GenIdMultiMap<string>();
+    const id = GenId.create(0, 1), newId = GenId.create(0, 2);
+    map.add(id, 'hello');
+    map.add(newId, 'world');
+
+    expect(map.getAll(id)).toEqual([]);
+    expect(map.getAll(newId)).toEqual(['world']);
+  });
+
+  test('add() should replace values of any other generation', () => {
+    const map = new GenIdMultiMap<string>();
+    const id = GenId.create(0, 2), otherId = GenId.create(0, 1);
+    map.add(id, 'hello');
+    map.add(otherId, 'world');
+
+    expect(map.size).toBe(1);
+    expect(map.has(id)).toBeFalsy();
+    expect(map.getAll(id)).toEqual([]);
+    expect(map.getAll(otherId)).toEqual(['world']);
+  });
+
+  test('getAll() should return empty array for non-existent key', () => {
+    const map = new GenIdMultiMap<string>();
+    expect(map.getAll(123)).toEqual([]);
+  });
+
+  test('deleteAll() should remove all values of key', () => {
+    const map = new GenIdMultiMap<string>();
+    const id = GenId.create(0, 1);
+    map.add(id, 'hello');
+    map.add(id, 'world');
+
+    expect(map.deleteAll(id)).toBeTruthy();
+    expect(map.deleteAll(id)).toBeFalsy();
+    expect(map.size).toBe(0);
+  });
+
+  test('deleteOne() should remove a single value and remove key when empty', () => {
+    const map = new GenIdMultiMap<string>();
+    const id = GenId.create(0, 1);
+    map.add(id, 'hello');
+    map.add(id, 'world');
+
+    expect(map.deleteOne(id, 'hello')).toBeTruthy();
+    expect(map.deleteOne(id, 'hello')).toBeFalsy();
+    expect(map.getAll(id)).toEqual(['world']);
+
+    expect(map.deleteOne(id, 'world')).toBeTruthy();
+    expect(map.has(id)).toBeFalsy();
+  });
+
+  test('clear() should empty the container', () => {
+    const map = new GenIdMultiMap<string>();
+    map.add(GenId.create(0, 1), '1');
+    map.add(GenId.create(1, 1), '2');
+
+    map.clear();
+    expect(map.size).toBe(0);
+  });
+
+  test('entries() should iterate through all ids and values', () => {
+    const map = new GenIdMultiMap<string>();
+    const id1 = GenId.create(0, 1), id2 = GenId.create(10, 2);
+    map.add(id1, 'a');
+    map.add(id2, 'b');
+    map.add(id1, 'c');
+
+    expect([...map.entries()]).toEqual([[id1, ['a', 'c']], [id2, ['b']]]);
+    expect([...map.keys()]).toEqual([id1, id2]);
+    expect([...map]).toEqual([...map.entries()]);
+  });
+});
@@ -16,9 +16,17 @@ export interface Arena<T, I extends number = number>
 
 /** An arena that uses generational index as key. */
 export class GenerationalArena<T, I extends number = number> implements Arena<T, I> {
-  private ids: IdGenerator<I> = new IdGenerator();
+  private readonly ids: IdGenerator<I>;
   private readonly data: T[] = [];
 
+  /**
+   * @param initialGeneration the generation of newly allocated keys. Defaults to 0.
+   * See {@link IdGenerator} for the valid range.
+   */
+  public constructor(initialGeneration = 0) {
+    this.ids = new IdGenerator(initialGeneration);
+  }
+
   public get size(): number {
     return this.ids.size;
   }
@@ -29,6 +37,42 @@ export class GenerationalArena<T, I extends number = number> implements Arena<T,
     return id;
   }
 
+  /**
+   * Adds a value to the arena and returns its key, like {@link add}.
+   * Returns undefined instead of reusing a slot whose generation has wrapped around. See {@link IdGenerator.tryAdd}.
+   */
+  public tryAdd(value: T): I | undefined {
+    const id = this.ids.tryAdd();
+    if (id !== undefined) {
+      this.data[indexOf(id)] = value;
+    }
+    return id;
+  }
+
+  /**
+   * Adds a value created from its own key to the arena and returns the key.
+   * If `create` throws, the key is released and the error is rethrown.
+   */
+  public addWithKey(create: (key: I) => T): I {
+    const id = this.ids.add();
+    try {
+      this.data[indexOf(id)] = create(id);
+    } catch (error) {
+      this.ids.delete(id);
+      throw error;
+    }
+    return id;
+  }
+
+  /** Adds multiple values to the arena and returns their keys in order. */
+  public addMany(values: Iterable<T>): I[] {
+    const ids: I[] = [];
+    for (const value of values) {
+      ids.push(this.add(value));
+    }
+    return ids;
+  }
+
   public clear(): void {
     this.ids.clear();
     this.data.length = 0;
@@ -58,6 +102,11 @@ export class GenerationalArena<T, I extends number = number> implements Arena<T,
     return this.ids.has(id) ? this.data[indexOf(id)] : undefined;
   }
 
+  /** Returns the generation of the live key at given index, or undefined if the index is free or out of range. */
+  public generationAt(index: number): number | undefined {
+    return this.ids.generationAt(index);
+  }
+
   public has(id: I): boolean {
     return this.ids.has(id);
   }
@@ -1,5 +1,5 @@
 import { Collection, ReadonlySet } from 'typescript';
-import { create as id, generationOf, indexOf, MAX_SAFE_GENERATION } from '../id';
+import { create as id, generationOf, indexOf, nextGeneration, MAX_SAFE_GENERATION } from '../id';
 
 /** Generator of values. */
 export interface Generator<T> extends Collection<T>, ReadonlySet<T>, Iterable<T> {
@@ -14,14 +14,28 @@ export interface Generator<T> extends Collection<T>, ReadonlySet<T>, Iterable<T>
 export class IdGenerator<T extends number = number> implements Generator<T> {
   private readonly generations: number[] = [];
   private readonly freeList: number[] = [];
+  private readonly exhausted: Set<number> = new Set();
+  private readonly initialGeneration: number;
+  private retiredCount = 0;
+
+  /**
+   * @param initialGeneration the generation of newly allocated indices. Defaults to 0.
+   * Clamped to an integer within [0, {@link MAX_SAFE_GENERATION}].
+   * Index 0 never uses generation 0, as the 0 Id is reserved for null.
+   */
+  public constructor(initialGeneration = 0) {
+    this.initialGeneration = Math.max(0, Math.min(Math.floor(initialGeneration) || 0, MAX_SAFE_GENERATION));
+  }
 
   public get size(): number {
-    return this.generations.length - this.freeList.length;
+    return this.generations.length - this.freeList.length - this.retiredCount;
   }
 
   public clear(): void {
     this.generations.length = 0;
     this.freeList.length = 0;
+    this.exhausted.clear();
+    this.retiredCount = 0;
   }
 
   public add(): T {
@@ -31,27 +45,45 @@ export class IdGenerator<T extends number = number> implements Generator<T> {
     if (this.freeList.length > 0) {
       // eslint-disable-next-line @typescript-eslint/no-non-null-assertion
       index = this.freeList.pop()!;
+      this.exhausted.delete(index);
       generation = Math.abs(this.generations[index]);
       this.generations[index] = generation;
     } else {
       index = this.generations.length;
-      generation = index ? 0 : 1; // avoids 0 Id as it is usually reserved for null.
+      // avoids 0 Id as it is usually reserved for null.
+      generation = (index || this.initialGeneration) ? this.initialGeneration : 1;
       this.generations.push(generation);
     }
 
     return id(index, generation);
   }
 
+  /**
+   * Creates a new ID like {@link add}, but returns undefined instead of reusing an index whose generation has
+   * wrapped around, as the new ID could be equal to a stale ID from before the wrap.
+   * The exhausted index is retired and never reused, so the next call allocates another index.
+   */
+  public tryAdd(): T | undefined {
+    const index = this.freeList[this.freeList.length - 1];
+    if (index !== undefined && this.exhausted.has(index)) {
+      this.freeList.pop();
+      this.exhausted.delete(index);
+      ++this.retiredCount;
+      return undefined;
+    }
+    return this.add();
+  }
+
   public delete(id: T): boolean {
     if (!this.has(id)) {
       return false;
     }
 
     const index = indexOf(id);
-    let generation = (this.generations[index] + 1) & MAX_SAFE_GENERATION;
-    if (!generation) {
-      ++generation; // avoids 0 Id as it is reserved for null.
+    if (this.generations[index] === MAX_SAFE_GENERATION) {
+      this.exhausted.add(index);
     }
+    const generation = nextGeneration(this.generations[index]);
 
     // Use negative sign to indicate free space
     this.generations[index] = -generation;
@@ -66,6 +98,12 @@ export class IdGenerator<T extends number = number> implements Generator<T> {
     }
   }
 
+  /** Returns the generation of the live ID at given index, or undefined if the index is free or out of range. */
+  public generationAt(index: number): number | undefined {
+    const generation = this.generations[index];
+    return generation >= 0 ? generation : undefined;
+  }
+
   public has(id: T): boolean {
     return (indexOf(id) < this.generations.length
       && generationOf(id) === this.generations[indexOf(id)]);
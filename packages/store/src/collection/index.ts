@@ -3,3 +3,4 @@ export * from './deque';
 export * from './generator';
 export * from './list';
 export * from './map';
+export * from './multimap';
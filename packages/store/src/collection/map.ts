@@ -8,6 +8,15 @@ export class GenIdMap<V, I extends number = number>
 {
   private readonly map: Map<number, [I, V]> = new Map();
 
+  /** Creates a map, optionally populated from the given id-value entries. */
+  public constructor(entries?: Iterable<readonly [I, V]>) {
+    if (entries) {
+      for (const [id, value] of entries) {
+        this.set(id, value);
+      }
+    }
+  }
+
   public get size(): number {
     return this.map.size;
   }
@@ -78,6 +87,15 @@ export class SparseSetMap<V, I extends number = number>
   private readonly ids: I[] = [];
   private readonly dense: V[] = [];
 
+  /** Creates a map, optionally populated from the given id-value entries. */
+  public constructor(entries?: Iterable<readonly [I, V]>) {
+    if (entries) {
+      for (const [id, value] of entries) {
+        this.set(id, value);
+      }
+    }
+  }
+
   public get size(): number {
     return this.dense.length;
   }
@@ -107,6 +125,15 @@ export class SparseSetMap<V, I extends number = number>
     return false;
   }
 
+  /** Deletes the values of multiple keys and returns the number of values deleted. */
+  public deleteMany(ids: Iterable<I>): number {
+    let count = 0;
+    for (const id of ids) {
+      this.delete(id) && ++count;
+    }
+    return count;
+  }
+
   public * entries(): IterableIterator<[I, V]> {
     for (let i = 0; i < this.ids.length; ++i) {
       yield [this.ids[i], this.dense[i]];
@@ -127,6 +154,11 @@ export class SparseSetMap<V, I extends number = number>
     return (this.ids[this.sparse[indexOf(id)]] === id);
   }
 
+  /** Returns whether any live id occupies given index, regardless of its generation. */
+  public hasIndex(index: number): boolean {
+    return this.ids[this.sparse[index]] !== undefined;
+  }
+
   public * keys(): IterableIterator<I> {
     for (let i = 0; i < this.ids.length; ++i) {
       yield this.ids[i];
@@ -146,6 +178,36 @@ export class SparseSetMap<V, I extends number = number>
     return this;
   }
 
+  /**
+   * Shrinks the sparse index array to fit the largest live index, releasing the memory held for
+   * higher indices that were deleted. The dense entries are left untouched.
+   */
+  public shrinkSparse(): void {
+    let maxIndex = -1;
+    for (const id of this.ids) {
+      maxIndex = Math.max(maxIndex, indexOf(id));
+    }
+    this.sparse.length = maxIndex + 1;
+  }
+
+  /**
+   * Removes all entries after the first `length` entries in iteration order.
+   * The last entries are the most recently inserted ones only if no delete() has since reordered them,
+   * so size can be used as a checkpoint to roll back to.
+   * Does nothing unless `length` is an integer within [0, size).
+   */
+  public truncate(length: number): void {
+    if (!Number.isInteger(length) || length < 0 || length >= this.ids.length) {
+      return;
+    }
+
+    for (let i = length; i < this.ids.length; ++i) {
+      this.sparse[indexOf(this.ids[i])] = -1;
+    }
+    this.ids.length = length;
+    this.dense.length = length;
+  }
+
   public * values(): IterableIterator<V> {
     for (let i = 0; i < this.ids.length; ++i) {
       yield this.dense[i];
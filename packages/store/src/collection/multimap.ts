@@ -0,0 +1,75 @@
+import { SparseSetMap } from './map';
+
+/** Multimap with generational index as key, which allows multiple values per key. */
+export class GenIdMultiMap<V, I extends number = number> implements Iterable<[I, readonly V[]]> {
+  private readonly map: SparseSetMap<V[], I> = new SparseSetMap();
+
+  /** Number of keys in the map. */
+  public get size(): number {
+    return this.map.size;
+  }
+
+  /**
+   * Adds a value to a key.
+   * If the index of the key holds values of any other generation, they are replaced, as with {@link SparseSetMap.set}:
+   * the last id added at an index is the live one, and other ids at that index yield no values.
+   */
+  public add(id: I, value: V): this {
+    const values = this.map.get(id);
+    if (values) {
+      values.push(value);
+    } else {
+      this.map.set(id, [value]);
+    }
+    return this;
+  }
+
+  /** Clears the map. */
+  public clear(): void {
+    this.map.clear();
+  }
+
+  /** Deletes all values of a key. */
+  public deleteAll(id: I): boolean {
+    return this.map.delete(id);
+  }
+
+  /** Deletes the first occurrence of a value from a key. The key is removed when it has no more values. */
+  public deleteOne(id: I, value: V): boolean {
+    const values = this.map.get(id);
+    const i = values ? values.indexOf(value) : -1;
+    if (!values || i < 0) {
+      return false;
+    }
+
+    values.splice(i, 1);
+    if (!values.length) {
+      this.map.delete(id);
+    }
+    return true;
+  }
+
+  /** Returns an iterable of keys and their values. */
+  public entries(): IterableIterator<[I, readonly V[]]> {
+    return this.map.entries();
+  }
+
+  /** Gets all values of a key. Returns an empty array if the key does not exist. */
+  public getAll(id: I): readonly V[] {
+    return this.map.get(id) ?? [];
+  }
+
+  /** Returns whether the key has any value. */
+  public has(id: I): boolean {
+    return this.map.has(id);
+  }
+
+  /** Returns an iterable of keys. */
+  public keys(): IterableIterator<I> {
+    return this.map.keys();
+  }
+
+  public [Symbol.iterator](): IterableIterator<[I, readonly V[]]> {
+    return this.entries();
+  }
+}
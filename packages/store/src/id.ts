@@ -3,14 +3,26 @@
  * @packageDocumentation
  */
 
+import { MUDS_DEBUG } from './config';
+
 export const MAX_SAFE_GENERATION = (1 << 21) - 1;
 export const UNIT_GENERATION = 2 ** 32;
 
 /** Creates a generational index ID from index and generation parts. */
 export function create<T extends number = number>(index: number, generation: number): T {
+  MUDS_DEBUG && assertRawParts(index, generation);
   return ((generation & MAX_SAFE_GENERATION) * UNIT_GENERATION + (index >>> 0)) as T;
 }
 
+/**
+ * Creates a generational index ID from index and generation parts.
+ * Returns undefined if either part is out of range, instead of truncating it like {@link create}.
+ */
+export function tryCreate<T extends number = number>(index: number, generation: number): T | undefined {
+  return (index === index >>> 0 && generation === (generation & MAX_SAFE_GENERATION))
+    ? create<T>(index, generation) : undefined;
+}
+
 /** Returns the index part (lower 32bit) of a generational index ID. */
 export function indexOf(id: number): number {
   return id >>> 0;
@@ -20,3 +32,42 @@ export function indexOf(id: number): number {
 export function generationOf(id: number): number {
   return (id / UNIT_GENERATION) & MAX_SAFE_GENERATION;
 }
+
+/** Returns the next generation value, wrapping to 1 after {@link MAX_SAFE_GENERATION}. */
+export function nextGeneration(generation: number): number {
+  // avoids 0 generation, as the 0 Id is reserved for null.
+  return ((generation + 1) & MAX_SAFE_GENERATION) || 1;
+}
+
+/**
+ * Returns the next generation value, saturating at {@link MAX_SAFE_GENERATION} instead of wrapping.
+ * A result equal to the input generation indicates that the generation is exhausted.
+ */
+export function saturatingNextGeneration(generation: number): number {
+  return Math.min(generation + 1, MAX_SAFE_GENERATION);
+}
+
+/** Encodes a generational index ID into 8 bytes of its float64 representation. Big-endian by default. */
+export function toBytes(id: number, littleEndian = false): Uint8Array {
+  const bytes = new Uint8Array(8);
+  new DataView(bytes.buffer).setFloat64(0, id, littleEndian);
+  return bytes;
+}
+
+/**
+ * Decodes a generational index ID from 8 bytes of its float64 representation. Big-endian by default.
+ * Returns undefined if the input is not exactly 8 bytes,
+ * or does not encode a valid ID, e.g. NaN, negative, fractional or out-of-range values.
+ */
+export function fromBytes<T extends number = number>(bytes: Uint8Array, littleEndian = false): T | undefined {
+  if (bytes.length !== 8) {
+    return undefined;
+  }
+  const id = new DataView(bytes.buffer, bytes.byteOffset, 8).getFloat64(0, littleEndian);
+  return create(indexOf(id), generationOf(id)) === id ? id as T : undefined;
+}
+
+function assertRawParts(index: number, generation: number) {
+  console.assert(index === index >>> 0, `Generational index out of range: ${index}`);
+  console.assert(generation === (generation & MAX_SAFE_GENERATION), `Generation out of range: ${generation}`);
+}
@@ -1,4 +1,4 @@
-import { MapGet } from './types';
+import { CollectionKeys, MapGet } from './types';
 
 /**
  * The RHS values of a unique join.
@@ -22,3 +22,26 @@ export function* uniqueJoin<L extends readonly unknown[], K, I extends MapGet<K,
   }
   iter.return?.();
 }
+
+/** Gets the values of multiple keys from a map, in order. Yields undefined for keys not in the map. */
+export function* getMany<K, V>(map: MapGet<K, V>, keys: Iterable<K>): IterableIterator<V | undefined> {
+  for (const key of keys) {
+    yield map.get(key);
+  }
+}
+
+/** Compares the keys of two collections, returning the keys added to, removed from, and common to both. */
+export function diffKeys<K>(
+  prev: CollectionKeys<K>, next: CollectionKeys<K>
+): [added: K[], removed: K[], common: K[]] {
+  const added: K[] = [];
+  const removed: K[] = [];
+  const common: K[] = [];
+  for (const key of prev.keys()) {
+    (next.has(key) ? common : removed).push(key);
+  }
+  for (const key of next.keys()) {
+    prev.has(key) || added.push(key);
+  }
+  return [added, removed, common];
+}
@@ -24,3 +24,14 @@ export interface MapGetSet<K, V> extends MapGet<K, V> {
   /** Deletes a value by key. */
   delete(key: K): boolean;
 }
+
+/**
+ * Interface for key membership and iteration of a collection.
+ */
+export interface CollectionKeys<K> {
+  /** Returns whether a key exists. */
+  has(key: K): boolean;
+
+  /** Returns an iterable of all keys. */
+  keys(): Iterable<K>;
+}